@@ -1,7 +1,113 @@
 mod anstream {
     pub mod adapter {
+        /// Split `s` into the plain-text runs between its `ESC [ ... <final>` CSI escape
+        /// sequences, so callers (width calculation, wrapping) never see escape bytes as
+        /// visible text.
         pub fn strip_str(s: &str) -> impl Iterator<Item = &str> {
-            [s].into_iter()
+            StripStr { remainder: s }
+        }
+
+        struct StripStr<'s> {
+            remainder: &'s str,
+        }
+
+        impl<'s> Iterator for StripStr<'s> {
+            type Item = &'s str;
+
+            fn next(&mut self) -> Option<&'s str> {
+                loop {
+                    if self.remainder.is_empty() {
+                        return None;
+                    }
+
+                    match self.remainder.find('\x1b') {
+                        None => {
+                            let text = self.remainder;
+                            self.remainder = "";
+                            return Some(text);
+                        }
+                        Some(0) => match parse_escape(self.remainder) {
+                            Some(len) => self.remainder = &self.remainder[len..],
+                            // Truncated or otherwise unrecognized escape; drop just the ESC
+                            // byte so we always make progress and never panic on malformed
+                            // input.
+                            None => self.remainder = &self.remainder[1..],
+                        },
+                        Some(esc) => {
+                            let text = &self.remainder[..esc];
+                            self.remainder = &self.remainder[esc..];
+                            return Some(text);
+                        }
+                    }
+                }
+            }
+        }
+
+        /// Parse a `ESC [ ... <final>` CSI sequence at the start of `s`, returning its byte
+        /// length, or `None` if `s` doesn't start with a complete one (e.g. it's truncated).
+        pub(super) fn parse_escape(s: &str) -> Option<usize> {
+            let bytes = s.as_bytes();
+            if bytes.first() != Some(&0x1b) || bytes.get(1) != Some(&b'[') {
+                return None;
+            }
+
+            let mut i = 2;
+            while let Some(&b) = bytes.get(i) {
+                match b {
+                    // parameter and intermediate bytes
+                    0x20..=0x3f => i += 1,
+                    // final byte
+                    0x40..=0x7e => return Some(i + 1),
+                    _ => return None,
+                }
+            }
+            None
+        }
+
+        /// Parse the `ESC [ params m` SGR sequence at the start of `s`, returning its
+        /// numeric parameters (an omitted or non-numeric field defaults to `0`, per the SGR
+        /// spec) and its total byte length. Returns `None` if `s` doesn't start with a
+        /// complete SGR escape, including a truncated one at the end of a buffer.
+        ///
+        /// Extended-color introducers (`38`/`48`, for truecolor/256-color fg/bg) consume a
+        /// variable number of following sub-parameters (`2;r;g;b` or `5;n`) that aren't
+        /// independent SGR codes in their own right — e.g. a `0` inside an RGB triple isn't
+        /// an SGR reset. ClassiCube chat only has 16 colors, so those sequences are dropped
+        /// along with their sub-parameters rather than fed through as if they were separate
+        /// codes.
+        pub(super) fn parse_sgr(s: &str) -> Option<(Vec<u16>, usize)> {
+            let len = parse_escape(s)?;
+            if s.as_bytes()[len - 1] != b'm' {
+                return None;
+            }
+
+            let body = &s[2..len - 1];
+            let raw: Vec<u16> = if body.is_empty() {
+                vec![0]
+            } else {
+                body.split(';').map(|p| p.parse().unwrap_or(0)).collect()
+            };
+
+            let mut params = Vec::with_capacity(raw.len());
+            let mut raw = raw.into_iter();
+            while let Some(param) = raw.next() {
+                if param == 38 || param == 48 {
+                    match raw.next() {
+                        Some(5) => {
+                            raw.next(); // palette index
+                        }
+                        Some(2) => {
+                            raw.next(); // r
+                            raw.next(); // g
+                            raw.next(); // b
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                params.push(param);
+            }
+            Some((params, len))
         }
     }
 }
@@ -38,6 +144,33 @@ mod anstyle {
         }
     }
 
+    /// Map an SGR foreground-color parameter (as parsed from a `ESC [ ... m` sequence) to
+    /// the matching ClassiCube color code. Returns `None` for parameters that aren't a
+    /// foreground color or reset, e.g. unsupported effects like bold or underline, which are
+    /// ignored rather than translated.
+    pub(super) fn sgr_fg_to_cc_code(param: u16) -> Option<&'static str> {
+        // Normal-intensity ANSI colors (30-37) map to the ClassiCube "dark" codes, in ANSI's
+        // black/red/green/yellow/blue/magenta/cyan/white order.
+        const NORMAL: [&str; 8] = ["&0", "&4", "&2", "&6", "&1", "&5", "&3", "&7"];
+        // High-intensity ANSI colors (90-97) map to the brighter ClassiCube codes, same order.
+        const BRIGHT: [&str; 8] = [
+            "&8",
+            classicube_helpers::color::RED,
+            classicube_helpers::color::LIME,
+            classicube_helpers::color::YELLOW,
+            "&9",
+            "&d",
+            "&b",
+            classicube_helpers::color::WHITE,
+        ];
+        match param {
+            0 | 39 => Some(classicube_helpers::color::WHITE),
+            30..=37 => Some(NORMAL[(param - 30) as usize]),
+            90..=97 => Some(BRIGHT[(param - 90) as usize]),
+            _ => None,
+        }
+    }
+
     #[derive(Copy, Clone, Debug)]
     pub enum Color {
         Ansi(AnsiColor),
@@ -96,6 +229,37 @@ impl StyledStr {
         self.0.push_str(msg);
     }
 
+    /// Append `msg`, translating any ANSI CSI/SGR escape sequences it contains (e.g. emitted
+    /// by [`color_print::cstr!`]) into the matching ClassiCube color code rather than storing
+    /// the raw escape bytes, which ClassiCube would otherwise render as garbage text.
+    fn push_ansi_str(&mut self, mut msg: &str) {
+        while let Some(esc) = msg.find('\x1b') {
+            self.0.push_str(&msg[..esc]);
+            msg = &msg[esc..];
+
+            match anstream::adapter::parse_sgr(msg) {
+                Some((params, len)) => {
+                    for param in params {
+                        if let Some(code) = anstyle::sgr_fg_to_cc_code(param) {
+                            self.0.push_str(code);
+                        }
+                    }
+                    msg = &msg[len..];
+                }
+                None => match anstream::adapter::parse_escape(msg) {
+                    // A complete CSI escape we have no translation for (e.g. cursor
+                    // movement); drop the whole sequence rather than letting its bytes
+                    // fall through as garbage text.
+                    Some(len) => msg = &msg[len..],
+                    // Truncated or otherwise unrecognized escape; drop just the ESC byte
+                    // so we always make progress and never panic on malformed input.
+                    None => msg = &msg[1..],
+                },
+            }
+        }
+        self.0.push_str(msg);
+    }
+
     pub(crate) fn trim(&mut self) {
         self.0 = self.0.trim().to_owned()
     }
@@ -137,9 +301,26 @@ impl StyledStr {
                     // over from a prior block of styled text
                     wrapper.reset();
                 }
-                let line = crate::output::textwrap::word_separators::find_words_ascii_space(line)
+
+                // ClassiCube `&X` color codes are zero-width in-game but plain text to the
+                // word wrapper, so leaving them in would count 2 extra columns per code and
+                // wrap lines too early. Splice each code out of the text handed to the
+                // wrapper and back in verbatim right where it was, so it affects neither the
+                // wrap column nor its own position in the output. (Reinjecting the active
+                // color after a break the wrapper itself inserts is `chat_lines`'s job, the
+                // only place that actually needs it.)
+                let mut rest = line;
+                while let Some((before, code, after)) = split_at_cc_code(rest) {
+                    let words =
+                        crate::output::textwrap::word_separators::find_words_ascii_space(before)
+                            .collect::<Vec<_>>();
+                    new.extend(wrapper.wrap(words));
+                    new.push_str(code);
+                    rest = after;
+                }
+                let words = crate::output::textwrap::word_separators::find_words_ascii_space(rest)
                     .collect::<Vec<_>>();
-                new.extend(wrapper.wrap(line));
+                new.extend(wrapper.wrap(words));
             }
         }
         if last != self.0.len() {
@@ -155,7 +336,7 @@ impl StyledStr {
     pub(crate) fn display_width(&self) -> usize {
         let mut width = 0;
         for c in self.iter_text() {
-            width += crate::output::display_width(c);
+            width += display_width_skipping_cc_codes(c);
         }
         width
     }
@@ -189,6 +370,107 @@ impl StyledStr {
 
         Ok(())
     }
+
+    /// Split this content into lines suitable for ClassiCube's chat: break on `\n`, and
+    /// further break any line exceeding ClassiCube's 255-byte chat message limit. ClassiCube
+    /// discards color state at the start of every chat message, so every line after the
+    /// first is prefixed with whichever `&X` color code was last active when it broke.
+    pub(crate) fn chat_lines(&self) -> Vec<String> {
+        const CHAT_LINE_MAX_LEN: usize = 255;
+
+        let mut lines = Vec::new();
+        let mut active: Option<&str> = None;
+        let mut current = String::new();
+
+        let mut chars = self.0.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if c == '&' {
+                if let Some(&(j, next)) = chars.peek() {
+                    if next.is_ascii_hexdigit() {
+                        // Treat `&X` as one atomic unit so the length check below never
+                        // splits a code across the chat line boundary.
+                        chars.next();
+                        let code = &self.0[i..j + next.len_utf8()];
+                        if current.len() + code.len() > CHAT_LINE_MAX_LEN {
+                            lines.push(std::mem::take(&mut current));
+                            if let Some(color) = active {
+                                current.push_str(color);
+                            }
+                        }
+                        current.push_str(code);
+                        active = Some(code);
+                        continue;
+                    }
+                }
+                // a lone `&` with no following nibble (e.g. at the end of the message) is
+                // literal and falls through to the generic handling below.
+            }
+
+            if c == '\n' {
+                lines.push(std::mem::take(&mut current));
+                if let Some(color) = active {
+                    current.push_str(color);
+                }
+                continue;
+            }
+
+            if current.len() + c.len_utf8() > CHAT_LINE_MAX_LEN {
+                lines.push(std::mem::take(&mut current));
+                if let Some(color) = active {
+                    current.push_str(color);
+                }
+            }
+            current.push(c);
+        }
+        lines.push(current);
+
+        lines
+    }
+}
+
+/// Whether `c` is a valid ClassiCube color code nibble, i.e. the `X` in `&X`.
+#[cfg(any(feature = "help", feature = "wrap_help"))]
+fn is_cc_color_nibble(c: char) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+/// Sum of [`crate::output::display_width`] over `text`, treating each `&X` ClassiCube color
+/// code as zero-width rather than two visible characters.
+#[cfg(feature = "help")]
+fn display_width_skipping_cc_codes(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '&' {
+            if let Some(&(_, next)) = chars.peek() {
+                if is_cc_color_nibble(next) {
+                    chars.next();
+                    continue;
+                }
+            }
+            // a lone `&` with no following nibble (e.g. at the end of the run) is literal
+        }
+        width += crate::output::display_width(&text[i..i + c.len_utf8()]);
+    }
+    width
+}
+
+/// Split `text` at its first ClassiCube `&X` color code, if any, returning the text before
+/// it, the code itself, and the text after it.
+#[cfg(feature = "wrap_help")]
+fn split_at_cc_code(text: &str) -> Option<(&str, &str, &str)> {
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '&' {
+            if let Some(&(j, next)) = chars.peek() {
+                if is_cc_color_nibble(next) {
+                    let end = j + next.len_utf8();
+                    return Some((&text[..i], &text[i..end], &text[end..]));
+                }
+            }
+        }
+    }
+    None
 }
 
 impl Default for &'_ StyledStr {
@@ -200,14 +482,14 @@ impl Default for &'_ StyledStr {
 
 impl From<std::string::String> for StyledStr {
     fn from(name: std::string::String) -> Self {
-        StyledStr(name)
+        StyledStr::from(&name)
     }
 }
 
 impl From<&'_ std::string::String> for StyledStr {
     fn from(name: &'_ std::string::String) -> Self {
         let mut styled = StyledStr::new();
-        styled.push_str(name);
+        styled.push_ansi_str(name);
         styled
     }
 }
@@ -215,7 +497,7 @@ impl From<&'_ std::string::String> for StyledStr {
 impl From<&'static str> for StyledStr {
     fn from(name: &'static str) -> Self {
         let mut styled = StyledStr::new();
-        styled.push_str(name);
+        styled.push_ansi_str(name);
         styled
     }
 }
@@ -5,6 +5,9 @@ use crate::util::color::ColorChoice;
 pub(crate) enum Stream {
     Stdout,
     Stderr,
+    /// There is no terminal inside a ClassiCube plugin, so help/error output is printed into
+    /// the in-game chat instead.
+    Chat,
 }
 
 #[derive(Clone, Debug)]
@@ -46,6 +49,12 @@ impl Colorizer {
                 let mut stderr = stderr.lock();
                 self.content.write_to(&mut stderr)
             }
+            Stream::Chat => {
+                for line in self.content.chat_lines() {
+                    classicube_helpers::chat::add(&line);
+                }
+                Ok(())
+            }
         }
     }
 }